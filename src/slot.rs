@@ -22,10 +22,10 @@ impl Slot {
         self._valid.contains(c)
     }
 
-    /// Creates a new Slot that will allow any character
-    pub fn new() -> Self {
+    /// Creates a new Slot that will allow any character in `alphabet`
+    pub fn new(alphabet: &HashSet<char>) -> Self {
         Slot {
-            _valid: HashSet::from_iter('a'..='z'),
+            _valid: alphabet.clone(),
         }
     }
 }
@@ -1,12 +1,84 @@
-use std::{collections::HashSet, fs::File, io::BufRead, io::BufReader, path::Path};
+use std::{
+    collections::{HashMap, HashSet},
+    fs::File,
+    io::BufRead,
+    io::BufReader,
+    path::Path,
+};
 
 use crate::{letter::Letter, slot::Slot};
 
-#[derive(Debug, Clone, Default)]
+/// Describes the shape of a Wordle-like game: how many letters an answer has and
+/// which characters may appear in it. `english5` is the classic 5-letter game;
+/// other configs support variants (Quordle-style lengths, accented alphabets, ...).
+#[derive(Debug, Clone)]
+pub struct WordleConfig {
+    pub length: usize,
+    pub alphabet: HashSet<char>,
+}
+
+impl WordleConfig {
+    /// The classic 5-letter, `'a'..='z'` English configuration
+    pub fn english5() -> Self {
+        WordleConfig {
+            length: 5,
+            alphabet: HashSet::from_iter('a'..='z'),
+        }
+    }
+}
+
+impl Default for WordleConfig {
+    fn default() -> Self {
+        WordleConfig::english5()
+    }
+}
+
+/// Supplies the two word lists a `Wordle` needs: the candidate answers (the
+/// shrinking set `update` filters) and the allowed guesses (an immutable, usually
+/// larger, pool of words you're permitted to type). Implement this to plug in a
+/// different backing store than a flat file, e.g. an embedded word list.
+pub trait WordSource {
+    fn answers(&self) -> Vec<String>;
+    fn guesses(&self) -> Vec<String>;
+}
+
+/// The default file-backed [`WordSource`]: reads the answers and guesses each from
+/// their own newline-separated file.
+pub struct FileWordSource<P: AsRef<Path>> {
+    pub answers_path: P,
+    pub guesses_path: P,
+}
+
+impl<P: AsRef<Path>> WordSource for FileWordSource<P> {
+    fn answers(&self) -> Vec<String> {
+        read_wordlist(&self.answers_path)
+    }
+
+    fn guesses(&self) -> Vec<String> {
+        read_wordlist(&self.guesses_path)
+    }
+}
+
+fn read_wordlist<T: AsRef<Path>>(path: T) -> Vec<String> {
+    let file = File::open(path).expect("Failed to open wordlist");
+    BufReader::new(file).lines().map(|w| w.unwrap()).collect()
+}
+
+fn matches_config(word: &str, config: &WordleConfig) -> bool {
+    word.chars().count() == config.length && word.chars().all(|c| config.alphabet.contains(&c))
+}
+
+#[derive(Debug, Clone)]
 pub struct Wordle {
+    // The shrinking set of words that could still be the answer.
     _wordlist: Vec<String>,
-    _slots: [Slot; 5],
-    _required: HashSet<char>,
+    // The immutable, usually larger, pool of words you're allowed to guess.
+    _guesses: Vec<String>,
+    _slots: Vec<Slot>,
+    // Per-letter (min, max) occurrence counts the answer must satisfy, derived from
+    // how many times a letter showed up as green/yellow vs. grey in a single guess.
+    _required: HashMap<char, (usize, usize)>,
+    _config: WordleConfig,
 }
 
 #[derive(Debug)]
@@ -21,37 +93,67 @@ impl Wordle {
     /// Prefixing a letter with ! is a grey, and blocks it from appearing in the results, unless that letter is already marked as required by another slot.
     /// A lowercase letter is yellow, marking it as requried for the wordle, but blocking it from that slot.
     /// A uppercase letter is green, marking it as requried for the wordle and making it the only acceptable letter for that slot.
+    /// A grey on a letter that also appears green/yellow elsewhere in the same line caps how many of that letter the answer can have, rather than ruling it out entirely.
     pub fn update<S: Into<String>>(&mut self, s: S) -> Result<(), WordleParseError> {
-        let line = Self::parse_line(s.into())?;
+        let line = Self::parse_line(s.into(), &self._config)?;
+
+        // Tally how many times each letter shows up as green/yellow ("present") in
+        // this line, so a grey on the same letter can be told apart from "absent"
+        // (present == 0) vs. "the answer has no more than this many" (present > 0).
+        let mut present: HashMap<char, usize> = HashMap::new();
+        for slot in &line {
+            match slot {
+                Letter::Green(c) | Letter::Yellow(c) => *present.entry(*c).or_insert(0) += 1,
+                Letter::Grey(_) => {}
+            }
+        }
 
         for (idx, slot) in line.iter().enumerate() {
             match slot {
                 // This slot may only have this character
                 Letter::Green(c) => {
                     self._slots[idx].restrict(*c);
-                    self._required.insert(*c);
                 }
 
                 // This slot can no longer have this character
                 Letter::Yellow(c) => {
                     self._slots[idx].remove(c);
-                    self._required.insert(*c);
                 }
 
-                // No slots may contain this character
+                // This slot can no longer have this character; if the letter didn't
+                // also show up as green/yellow elsewhere in the line, it's truly
+                // absent, so rule it out of every slot too.
                 Letter::Grey(c) => {
-                    for slot in &mut self._slots {
-                        slot.remove(c);
+                    self._slots[idx].remove(c);
+
+                    if present.get(c).copied().unwrap_or(0) == 0 {
+                        for slot in &mut self._slots {
+                            slot.remove(c);
+                        }
                     }
                 }
             }
         }
 
+        for (&c, &count) in present.iter() {
+            let entry = self._required.entry(c).or_insert((0, usize::MAX));
+            entry.0 = entry.0.max(count);
+        }
+
+        for slot in &line {
+            if let Letter::Grey(c) = slot {
+                let count = present.get(c).copied().unwrap_or(0);
+                self._required.entry(*c).or_insert((0, usize::MAX)).1 = count;
+            }
+        }
+
         self._wordlist.retain(|word| {
-            ({
-                let slots = &self._slots;
-                word.chars().enumerate().all(|(i, c)| slots[i].contains(&c))
-            }) && self._required.iter().all(|c| word.contains(*c))
+            let slots = &self._slots;
+            word.chars().enumerate().all(|(i, c)| slots[i].contains(&c))
+                && self._required.iter().all(|(&c, &(min, max))| {
+                    let count = word.matches(c).count();
+                    count >= min && count <= max
+                })
         });
 
         Ok(())
@@ -66,7 +168,171 @@ impl Wordle {
         self._wordlist.clone().into_iter()
     }
 
-    fn parse_line<S: Into<String>>(s: S) -> Result<[Letter; 5], WordleParseError> {
+    /// Ranks candidate guesses (drawn from the allowed guess pool) by expected
+    /// information gain against the current wordlist, returning the top `n` by
+    /// Shannon entropy descending.
+    ///
+    /// For each candidate guess, every still-possible answer is hashed into one of
+    /// `3^length` feedback patterns via [`Self::pattern_key`], and the entropy of the
+    /// resulting distribution estimates how much a guess will narrow the wordlist.
+    pub fn suggest(&self, n: usize) -> Vec<(String, f64)> {
+        let total = self._wordlist.len() as f64;
+        let bucket_count = 3usize.pow(self._config.length as u32);
+
+        let mut scored: Vec<(String, f64)> = self
+            ._guesses
+            .iter()
+            .map(|guess| {
+                let mut buckets = vec![0usize; bucket_count];
+                for answer in &self._wordlist {
+                    buckets[Self::pattern_key(guess, answer)] += 1;
+                }
+
+                let entropy: f64 = buckets
+                    .iter()
+                    .filter(|&&count| count > 0)
+                    .map(|&count| {
+                        let p = count as f64 / total;
+                        -p * p.log2()
+                    })
+                    .sum();
+
+                (guess.clone(), entropy)
+            })
+            .collect();
+
+        scored.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap());
+        scored.truncate(n);
+        scored
+    }
+
+    /// Computes the base-3 feedback pattern (0=grey, 1=yellow, 2=green per slot)
+    /// that guessing `guess` would produce against `answer`, encoded as a single
+    /// integer in `0..3^length`. Duplicate letters are resolved the same way real
+    /// Wordle does: greens are matched first and consumed from a per-letter tally,
+    /// then yellows are awarded only while unconsumed occurrences remain.
+    fn pattern_key(guess: &str, answer: &str) -> usize {
+        let guess: Vec<char> = guess.chars().collect();
+        let answer: Vec<char> = answer.chars().collect();
+        let len = guess.len();
+        let mut digits = vec![0usize; len];
+        let mut tally: HashMap<char, usize> = HashMap::new();
+
+        for &c in &answer {
+            *tally.entry(c).or_insert(0) += 1;
+        }
+
+        for i in 0..len {
+            if guess[i] == answer[i] {
+                digits[i] = 2;
+                *tally.get_mut(&guess[i]).unwrap() -= 1;
+            }
+        }
+
+        for i in 0..len {
+            if digits[i] == 2 {
+                continue;
+            }
+
+            if let Some(count) = tally.get_mut(&guess[i]) {
+                if *count > 0 {
+                    digits[i] = 1;
+                    *count -= 1;
+                }
+            }
+        }
+
+        digits.iter().fold(0, |acc, &d| acc * 3 + d)
+    }
+
+    /// Computes the standard green/yellow/grey Wordle feedback for guessing `guess`
+    /// when `answer` is the true word, one [`Letter`] per position in `guess`. Greens
+    /// are matched first and consumed from a per-letter tally of `answer`; a letter
+    /// is only yellow if an unconsumed occurrence remains, otherwise it's grey.
+    ///
+    /// Works for any word length (not just 5), matching [`Self::pattern_key`].
+    pub fn score(guess: &str, answer: &str) -> Vec<Letter> {
+        let guess: Vec<char> = guess.chars().collect();
+        let answer: Vec<char> = answer.chars().collect();
+        let len = guess.len();
+        let mut tally: HashMap<char, usize> = HashMap::new();
+
+        for &c in &answer {
+            *tally.entry(c).or_insert(0) += 1;
+        }
+
+        let mut result: Vec<Letter> = (0..len).map(|_| Letter::Grey(' ')).collect();
+
+        for i in 0..len {
+            if guess[i] == answer[i] {
+                result[i] = Letter::Green(guess[i]);
+                *tally.get_mut(&guess[i]).unwrap() -= 1;
+            }
+        }
+
+        for i in 0..len {
+            if matches!(result[i], Letter::Green(_)) {
+                continue;
+            }
+
+            match tally.get_mut(&guess[i]) {
+                Some(count) if *count > 0 => {
+                    result[i] = Letter::Yellow(guess[i]);
+                    *count -= 1;
+                }
+                _ => result[i] = Letter::Grey(guess[i]),
+            }
+        }
+
+        result
+    }
+
+    /// Auto-solves for `answer` by repeatedly picking the best-entropy guess (falling
+    /// back to the first remaining candidate), scoring it, and folding the feedback
+    /// back through [`Self::update`]. Returns the sequence of guesses on success, or
+    /// `None` if `answer` isn't found within `max_guesses`.
+    pub fn solve(&mut self, answer: &str, max_guesses: usize) -> Option<Vec<String>> {
+        let mut guesses = Vec::new();
+
+        for _ in 0..max_guesses {
+            // With one or two answers left, playing a candidate already gains as
+            // much information as any guess can (it tells the two apart), and only
+            // an actual candidate can win outright, so play one instead of wasting
+            // a guess on a non-winning probe word.
+            let guess = if self._wordlist.len() <= 2 {
+                self._wordlist.first().cloned()
+            } else {
+                self.suggest(1).into_iter().next().map(|(word, _)| word)
+            }
+            .or_else(|| self._wordlist.first().cloned())?;
+
+            guesses.push(guess.clone());
+
+            if guess == answer {
+                return Some(guesses);
+            }
+
+            let feedback = Self::score(&guess, answer);
+            let line: String = feedback
+                .iter()
+                .map(|letter| match letter {
+                    Letter::Green(c) => c.to_uppercase().to_string(),
+                    Letter::Yellow(c) => c.to_ascii_lowercase().to_string(),
+                    Letter::Grey(c) if *c == ' ' => "?".to_string(),
+                    Letter::Grey(c) => format!("!{c}"),
+                })
+                .collect();
+
+            self.update(line).ok()?;
+        }
+
+        None
+    }
+
+    fn parse_line<S: Into<String>>(
+        s: S,
+        config: &WordleConfig,
+    ) -> Result<Vec<Letter>, WordleParseError> {
         let mut letters = Vec::<Letter>::new();
         let mut block = false;
 
@@ -82,16 +348,88 @@ impl Wordle {
             } else {
                 letters.push(match ch {
                     '?' => Letter::Grey(' '),
-                    c @ 'a'..='z' => Letter::Yellow(c),
-                    c @ 'A'..='Z' => Letter::Green(c),
+                    c if c.is_lowercase() && config.alphabet.contains(&c) => Letter::Yellow(c),
+                    c if c.is_uppercase()
+                        && config
+                            .alphabet
+                            .contains(&c.to_lowercase().next().unwrap_or(c)) =>
+                    {
+                        // Normalize to lowercase so a green letter compares equal to
+                        // the (always lowercase) candidates in the wordlist.
+                        Letter::Green(c.to_lowercase().next().unwrap_or(c))
+                    }
                     c => return Err(WordleParseError::InvalidToken(c)),
                 })
             }
         }
 
-        match <[Letter; 5]>::try_from(letters.as_slice()) {
-            Ok(line) => Ok(line),
-            Err(_) => Err(WordleParseError::InvalidLength(letters.len())),
+        if letters.len() != config.length {
+            return Err(WordleParseError::InvalidLength(letters.len()));
+        }
+
+        Ok(letters)
+    }
+
+    /// Creates a `Wordle` from a single newline-separated word list at `path`, using
+    /// a custom `config` instead of the default 5-letter English one. The same list
+    /// is used as both the answer and the guess pool.
+    pub fn with_config<T: AsRef<Path>>(path: T, config: WordleConfig) -> Self {
+        let wordlist: Vec<String> = read_wordlist(path)
+            .into_iter()
+            .filter(|w| matches_config(w, &config))
+            .collect();
+
+        Wordle {
+            _guesses: wordlist.clone(),
+            _wordlist: wordlist,
+            _slots: (0..config.length)
+                .map(|_| Slot::new(&config.alphabet))
+                .collect(),
+            _required: HashMap::new(),
+            _config: config,
+        }
+    }
+
+    /// Creates a `Wordle` from a pluggable [`WordSource`], using `config` to shape
+    /// and validate both lists it returns.
+    pub fn from_source<S: WordSource>(source: S, config: WordleConfig) -> Self {
+        Wordle {
+            _wordlist: source
+                .answers()
+                .into_iter()
+                .filter(|w| matches_config(w, &config))
+                .collect(),
+            _guesses: source
+                .guesses()
+                .into_iter()
+                .filter(|w| matches_config(w, &config))
+                .collect(),
+            _slots: (0..config.length)
+                .map(|_| Slot::new(&config.alphabet))
+                .collect(),
+            _required: HashMap::new(),
+            _config: config,
+        }
+    }
+
+    /// Creates a `Wordle` directly from an already-prepared answer list and guess
+    /// pool, validated and filtered against `config`, so `suggest` can propose
+    /// high-information "probe" words that can't themselves be the answer.
+    pub fn from_lists(answers: Vec<String>, guesses: Vec<String>, config: WordleConfig) -> Self {
+        Wordle {
+            _wordlist: answers
+                .into_iter()
+                .filter(|w| matches_config(w, &config))
+                .collect(),
+            _guesses: guesses
+                .into_iter()
+                .filter(|w| matches_config(w, &config))
+                .collect(),
+            _slots: (0..config.length)
+                .map(|_| Slot::new(&config.alphabet))
+                .collect(),
+            _required: HashMap::new(),
+            _config: config,
         }
     }
 }
@@ -101,30 +439,19 @@ where
     T: AsRef<Path>,
 {
     fn from(path: T) -> Self {
-        let wordlist = File::open(path).expect("Failed to open wordlist");
-        let buf = BufReader::new(wordlist);
-        Wordle {
-            _wordlist: buf
-                .lines()
-                .map(|w| w.unwrap())
-                .filter(|w| w.len() == 5)
-                .collect(),
-            _slots: [
-                Slot::new(),
-                Slot::new(),
-                Slot::new(),
-                Slot::new(),
-                Slot::new(),
-            ],
-            _required: HashSet::new(),
-        }
+        Wordle::with_config(path, WordleConfig::english5())
     }
 }
 
 #[cfg(test)]
 mod test {
+    use std::collections::HashSet;
+
     use super::Letter::*;
-    use crate::{slot::Slot, wordle::Wordle};
+    use crate::{
+        slot::Slot,
+        wordle::{Wordle, WordleConfig, WordleParseError, WordSource},
+    };
 
     fn filter<S: Into<String>>(word: S, slots: &[Slot; 5]) -> bool {
         word.into()
@@ -135,13 +462,15 @@ mod test {
 
     #[test]
     fn parse() {
+        let config = WordleConfig::english5();
+
         assert_eq!(
-            Wordle::parse_line("c!r!an!e").unwrap(),
-            [Yellow('c'), Grey('r'), Grey('a'), Yellow('n'), Grey('e')]
+            Wordle::parse_line("c!r!an!e", &config).unwrap(),
+            vec![Yellow('c'), Grey('r'), Grey('a'), Yellow('n'), Grey('e')]
         );
         assert_eq!(
-            Wordle::parse_line("!p!lo!y!s").unwrap(),
-            [Grey('p'), Grey('l'), Yellow('o'), Grey('y'), Grey('s')]
+            Wordle::parse_line("!p!lo!y!s", &config).unwrap(),
+            vec![Grey('p'), Grey('l'), Yellow('o'), Grey('y'), Grey('s')]
         );
     }
 
@@ -159,6 +488,177 @@ mod test {
         assert!(!filter("zeinu", &slots));
     }
 
+    #[test]
+    fn pattern_key_extremes() {
+        // An exact match is all greens, the highest possible key.
+        assert_eq!(Wordle::pattern_key("crane", "crane"), 242);
+
+        // No shared letters at all is all greys, the lowest possible key.
+        assert_eq!(Wordle::pattern_key("might", "close"), 0);
+    }
+
+    #[test]
+    fn score_duplicate_letters() {
+        // "sassy" has three s's, but "chess" only has two: the fourth-letter s
+        // lines up directly (green), the first-letter s is elsewhere in the
+        // answer (yellow), and the extra third-letter s has nothing left to
+        // match (grey) instead of wrongly scoring as another hit.
+        assert_eq!(
+            Wordle::score("sassy", "chess"),
+            vec![Yellow('s'), Grey('a'), Grey('s'), Green('s'), Grey('y')]
+        );
+    }
+
+    #[test]
+    fn score_and_solve_non_default_length() {
+        // score() and solve() must work off the guess/answer length, not a
+        // hardcoded 5, so a 4-letter game (as custom_length_config exercises)
+        // doesn't panic on out-of-bounds indexing.
+        assert_eq!(
+            Wordle::score("abcd", "abdc"),
+            vec![Green('a'), Green('b'), Yellow('c'), Yellow('d')]
+        );
+
+        let config = WordleConfig {
+            length: 4,
+            alphabet: HashSet::from_iter('a'..='d'),
+        };
+        let answers = vec![
+            "abcd".to_string(),
+            "bcda".to_string(),
+            "dcba".to_string(),
+        ];
+        let mut wordle = Wordle::from_lists(answers.clone(), answers, config);
+
+        let guesses = wordle.solve("dcba", 6).expect("should find the answer");
+        assert_eq!(guesses.last(), Some(&"dcba".to_string()));
+    }
+
+    #[test]
+    fn update_accepts_uppercase_green_letters() {
+        // An all-green line comes in uppercase (see parse_line); it must still
+        // match the (always lowercase) candidates in the wordlist, or a correct
+        // guess would wipe out every remaining word instead of narrowing to it.
+        let mut wordle = Wordle::from_lists(
+            vec!["crane".to_string(), "speed".to_string()],
+            Vec::new(),
+            WordleConfig::english5(),
+        );
+
+        assert!(wordle.update("CRANE").is_ok());
+
+        let words: Vec<String> = wordle.words().into_iter().collect();
+        assert_eq!(words, vec!["crane".to_string()]);
+    }
+
+    #[test]
+    fn solve_finds_the_answer() {
+        let answers = vec![
+            "crane".to_string(),
+            "speed".to_string(),
+            "sheep".to_string(),
+            "stork".to_string(),
+        ];
+        let mut wordle = Wordle::from_lists(answers.clone(), answers, WordleConfig::english5());
+
+        let guesses = wordle.solve("sheep", 6).expect("should find the answer");
+        assert_eq!(guesses.last(), Some(&"sheep".to_string()));
+    }
+
+    #[test]
+    fn exact_letter_count() {
+        // "e!e???": one yellow e, then a grey e -- the answer must have exactly one e.
+        let mut wordle = Wordle::from_lists(
+            vec![
+                "bakes".to_string(),
+                "sheep".to_string(),
+                "stork".to_string(),
+            ],
+            Vec::new(),
+            WordleConfig::english5(),
+        );
+
+        assert!(wordle.update("e!e???").is_ok());
+
+        let words: Vec<String> = wordle.words().into_iter().collect();
+        assert_eq!(words, vec!["bakes".to_string()]);
+    }
+
+    #[test]
+    fn custom_length_config() {
+        // A 4-letter game over a restricted alphabet should reject anything that
+        // doesn't fit that shape, independent of the default 5-letter English config.
+        let config = WordleConfig {
+            length: 4,
+            alphabet: HashSet::from_iter('a'..='d'),
+        };
+
+        assert!(matches!(
+            Wordle::parse_line("abc", &config),
+            Err(WordleParseError::InvalidLength(_))
+        ));
+        assert!(matches!(
+            Wordle::parse_line("xbcd", &config),
+            Err(WordleParseError::InvalidToken('x'))
+        ));
+        assert!(Wordle::parse_line("Abcd", &config).is_ok());
+    }
+
+    #[test]
+    fn suggest_draws_from_the_guess_pool() {
+        // "zzzzz" can never be the answer but is allowed as a probe guess, so it
+        // must show up in suggest()'s ranking even though words() never will.
+        let wordle = Wordle::from_lists(
+            vec!["crane".to_string(), "speed".to_string()],
+            vec!["crane".to_string(), "speed".to_string(), "zzzzz".to_string()],
+            WordleConfig::default(),
+        );
+
+        let suggestions: Vec<String> = wordle.suggest(3).into_iter().map(|(w, _)| w).collect();
+        assert!(suggestions.contains(&"zzzzz".to_string()));
+
+        let words: Vec<String> = wordle.words().into_iter().collect();
+        assert!(!words.contains(&"zzzzz".to_string()));
+    }
+
+    #[test]
+    fn from_lists_filters_out_words_that_dont_match_the_config() {
+        // A word with the wrong length or an out-of-alphabet character must be
+        // dropped from both lists, just like with_config/from_source do.
+        let wordle = Wordle::from_lists(
+            vec!["crane".to_string(), "tiny".to_string()],
+            vec!["crane".to_string(), "speed".to_string(), "crate!".to_string()],
+            WordleConfig::english5(),
+        );
+
+        let words: Vec<String> = wordle.words().into_iter().collect();
+        assert_eq!(words, vec!["crane".to_string()]);
+        assert_eq!(wordle.suggest(10).len(), 2);
+    }
+
+    struct FakeWordSource;
+
+    impl WordSource for FakeWordSource {
+        fn answers(&self) -> Vec<String> {
+            vec!["crane".to_string(), "speed".to_string()]
+        }
+
+        fn guesses(&self) -> Vec<String> {
+            vec!["crane".to_string(), "speed".to_string(), "zzzzz".to_string()]
+        }
+    }
+
+    #[test]
+    fn from_source_pulls_answers_and_guesses_from_the_source() {
+        let wordle = Wordle::from_source(FakeWordSource, WordleConfig::english5());
+
+        let words: Vec<String> = wordle.words().into_iter().collect();
+        assert_eq!(words, vec!["crane".to_string(), "speed".to_string()]);
+
+        let suggestions: Vec<String> = wordle.suggest(3).into_iter().map(|(w, _)| w).collect();
+        assert!(suggestions.contains(&"zzzzz".to_string()));
+    }
+
     #[test]
     fn tonic() {
         let mut wordle = Wordle::from("/home/alefir/.local/share/wordle_words");